@@ -0,0 +1,110 @@
+use ggez::{Context, GameResult};
+use ggez::conf::WindowMode;
+use ggez::event::{EventHandler, MouseButton};
+use ggez::graphics::{self, Color, DrawParam, Text};
+
+use crate::core::difficulty::Difficulty;
+use crate::core::game::GameState;
+
+// The app starts on a difficulty menu and moves into a game once one is chosen.
+enum AppState {
+    Menu,
+    Playing(GameState),
+}
+
+pub struct App {
+    state: AppState,
+}
+
+impl App {
+    pub fn new() -> Self {
+        App { state: AppState::Menu }
+    }
+
+    fn start_game(&mut self, ctx: &mut Context, difficulty: Difficulty) -> GameResult {
+        let (width, height) = difficulty.screen_size();
+        ctx.gfx.set_mode(WindowMode::default().dimensions(width, height))?;
+        self.state = AppState::Playing(GameState::new(difficulty));
+        Ok(())
+    }
+}
+
+impl EventHandler for App {
+    fn update(&mut self, ctx: &mut Context) -> GameResult {
+        if let AppState::Playing(game) = &mut self.state {
+            game.update(ctx)?;
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, ctx: &mut Context) -> GameResult {
+        match &mut self.state {
+            AppState::Menu => {
+                let mut canvas = graphics::Canvas::from_frame(ctx, Color::WHITE);
+
+                canvas.draw(
+                    &Text::new("Minesweeper - choose a difficulty"),
+                    DrawParam::default().dest([20.0, 20.0]).color(Color::BLACK),
+                );
+                canvas.draw(
+                    &Text::new("1: Beginner (9x9, 10 mines)"),
+                    DrawParam::default().dest([20.0, 60.0]).color(Color::BLACK),
+                );
+                canvas.draw(
+                    &Text::new("2: Intermediate (16x16, 40 mines)"),
+                    DrawParam::default().dest([20.0, 90.0]).color(Color::BLACK),
+                );
+                canvas.draw(
+                    &Text::new("3: Expert (24x24, 99 mines)"),
+                    DrawParam::default().dest([20.0, 120.0]).color(Color::BLACK),
+                );
+
+                canvas.finish(ctx)?;
+            }
+            AppState::Playing(game) => game.draw(ctx)?,
+        }
+
+        Ok(())
+    }
+
+    fn mouse_button_down_event(
+        &mut self,
+        ctx: &mut Context,
+        button: MouseButton,
+        x: f32,
+        y: f32,
+    ) -> GameResult {
+        if let AppState::Playing(game) = &mut self.state {
+            game.mouse_button_down_event(ctx, button, x, y)?;
+        }
+
+        Ok(())
+    }
+
+    fn key_down_event(
+        &mut self,
+        ctx: &mut Context,
+        input: ggez::input::keyboard::KeyInput,
+        repeated: bool,
+    ) -> GameResult {
+        match &mut self.state {
+            AppState::Menu => {
+                use ggez::input::keyboard::KeyCode;
+
+                let difficulty = match input.keycode {
+                    Some(KeyCode::Key1) => Some(Difficulty::BEGINNER),
+                    Some(KeyCode::Key2) => Some(Difficulty::INTERMEDIATE),
+                    Some(KeyCode::Key3) => Some(Difficulty::EXPERT),
+                    _ => None,
+                };
+
+                if let Some(difficulty) = difficulty {
+                    self.start_game(ctx, difficulty)?;
+                }
+            }
+            AppState::Playing(game) => game.key_down_event(ctx, input, repeated)?,
+        }
+
+        Ok(())
+    }
+}
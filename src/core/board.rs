@@ -0,0 +1,247 @@
+use rand::{self, Rng};
+use serde::{Deserialize, Serialize};
+use tinyvec::ArrayVec;
+
+use crate::core::cell::{Cell, Mark};
+use crate::core::difficulty::Difficulty;
+
+// A cell has at most 8 neighbors, so their coordinates fit in a fixed-size,
+// stack-allocated buffer instead of a heap-allocated `Vec`.
+type NeighborBuffer = ArrayVec<[(usize, usize); 8]>;
+
+// Board holds the pure minesweeper logic: the grid and its derived state.
+// It has no dependency on ggez, so it can be driven and inspected headlessly
+// by the solver, the text renderer, and tests, independent of rendering or
+// timing concerns (which live on `GameState`).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Board {
+    pub(crate) difficulty: Difficulty,
+    pub(crate) grid: Vec<Vec<Cell>>,
+    pub(crate) game_over: bool,
+    pub(crate) won: bool,
+    pub(crate) unrevealed_non_mine_count: usize,
+    // Mines are placed lazily, on the first reveal, so that click can never
+    // be a mine.
+    pub(crate) mines_placed: bool,
+    pub(crate) flagged_count: usize,
+    // Set whenever a move just ended the game (win or loss), so the caller
+    // can react (stop the timer, record a best time) without Board itself
+    // knowing anything about timers or persistence. Cleared by the caller.
+    #[serde(skip)]
+    pub(crate) just_finished: bool,
+}
+
+impl Board {
+    pub fn new(difficulty: Difficulty) -> Self {
+        Board {
+            difficulty,
+            grid: vec![vec![Cell::new(); difficulty.cols]; difficulty.rows],
+            game_over: false,
+            won: false,
+            unrevealed_non_mine_count: difficulty.rows * difficulty.cols - difficulty.mines,
+            mines_placed: false,
+            flagged_count: 0,
+            just_finished: false,
+        }
+    }
+
+    // Number of mines not yet accounted for by a flag; can go negative in
+    // spirit but is clamped at zero for display.
+    pub(crate) fn mines_remaining(&self) -> usize {
+        self.difficulty.mines.saturating_sub(self.flagged_count)
+    }
+
+    // Place mines randomly on the grid, excluding the cell the player just
+    // clicked and its 8 neighbors so the first click is always safe.
+    fn place_mines(&mut self, safe_row: usize, safe_col: usize) {
+        let mut rng = rand::thread_rng();
+        let mut mines_placed = 0;
+
+        while mines_placed < self.difficulty.mines {
+            let row = rng.gen_range(0..self.difficulty.rows);
+            let col = rng.gen_range(0..self.difficulty.cols);
+
+            let in_safe_zone = row.abs_diff(safe_row) <= 1 && col.abs_diff(safe_col) <= 1;
+
+            if !in_safe_zone && !self.grid[row][col].is_mine {
+                self.grid[row][col].is_mine = true;
+                mines_placed += 1;
+            }
+        }
+    }
+
+    // Calculate the number of adjacent mines for each cell
+    pub(crate) fn calculate_adjacent_mines(&mut self) {
+        for row in 0..self.difficulty.rows {
+            for col in 0..self.difficulty.cols {
+                if self.grid[row][col].is_mine {
+                    continue;
+                }
+
+                let mut count = 0;
+                self.for_each_adjacent_cell(row, col, |r, c| {
+                    if self.grid[r][c].is_mine {
+                        count += 1;
+                    }
+                });
+
+                self.grid[row][col].adjacent_mines = count;
+            }
+        }
+    }
+
+    // Reveal a cell and handle the result
+    pub(crate) fn reveal_cell(&mut self, row: usize, col: usize) {
+        if self.game_over || self.won || self.grid[row][col].is_revealed || self.grid[row][col].mark == Mark::Flag {
+            return;
+        }
+
+        // The first reveal places the mines, keeping this cell and its
+        // neighbors safe, then derives the adjacency counts from them.
+        if !self.mines_placed {
+            self.place_mines(row, col);
+            self.calculate_adjacent_mines();
+            self.mines_placed = true;
+        }
+
+        self.grid[row][col].is_revealed = true;
+
+        // If it's a mine, game over
+        if self.grid[row][col].is_mine {
+            self.game_over = true;
+            self.just_finished = true;
+            return;
+        }
+
+        // Decrement the unrevealed non-mine count
+        self.unrevealed_non_mine_count -= 1;
+
+        // If it's a cell with no adjacent mines, reveal adjacent cells
+        if self.grid[row][col].adjacent_mines == 0 {
+            // Collect adjacent cells into a stack buffer to avoid both the
+            // borrow conflict of revealing while iterating and a heap
+            // allocation on every step of the flood fill.
+            for (new_row, new_col) in self.neighbor_coords(row, col) {
+                if !self.grid[new_row][new_col].is_revealed && self.grid[new_row][new_col].mark != Mark::Flag {
+                    self.reveal_cell(new_row, new_col);
+                }
+            }
+        }
+
+        // Check if the player has won
+        self.check_win();
+    }
+
+    // Cycle a cell's mark: None -> Flag -> Question -> None
+    pub(crate) fn cycle_mark(&mut self, row: usize, col: usize) {
+        if self.game_over || self.won || self.grid[row][col].is_revealed {
+            return;
+        }
+
+        let previous_mark = self.grid[row][col].mark;
+        let next_mark = previous_mark.next();
+        self.grid[row][col].mark = next_mark;
+
+        if previous_mark == Mark::Flag {
+            self.flagged_count -= 1;
+        }
+        if next_mark == Mark::Flag {
+            self.flagged_count += 1;
+        }
+
+        // Check if the player has won
+        self.check_win();
+    }
+
+    // Set a cell's mark directly to Flag, used by the solver which needs to
+    // flag a cell deterministically rather than cycle through marks.
+    pub(crate) fn flag_cell(&mut self, row: usize, col: usize) {
+        if self.game_over || self.won || self.grid[row][col].is_revealed || self.grid[row][col].mark == Mark::Flag {
+            return;
+        }
+
+        self.grid[row][col].mark = Mark::Flag;
+        self.flagged_count += 1;
+        self.check_win();
+    }
+
+    // Helper function to iterate over adjacent cells
+    pub(crate) fn for_each_adjacent_cell<F>(&self, row: usize, col: usize, mut callback: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        for (r, c) in self.neighbor_coords(row, col) {
+            callback(r, c);
+        }
+    }
+
+    // Collect the in-bounds neighbor coordinates of (row, col) into a
+    // stack-allocated buffer; a cell has at most 8 neighbors, so this never
+    // spills to the heap the way a `Vec` would on every call.
+    fn neighbor_coords(&self, row: usize, col: usize) -> NeighborBuffer {
+        let mut neighbors = NeighborBuffer::new();
+
+        for dr in -1..=1 {
+            for dc in -1..=1 {
+                if dr == 0 && dc == 0 {
+                    continue;
+                }
+
+                let new_row = row as isize + dr;
+                let new_col = col as isize + dc;
+
+                if new_row >= 0 && new_row < self.difficulty.rows as isize &&
+                   new_col >= 0 && new_col < self.difficulty.cols as isize {
+                    neighbors.push((new_row as usize, new_col as usize));
+                }
+            }
+        }
+
+        neighbors
+    }
+
+    // Chord a revealed numbered cell: if its count of adjacent mines is
+    // already satisfied by the flags around it, reveal every remaining
+    // unflagged, unrevealed neighbor. This can cascade and can trigger a
+    // loss if a flag was placed on the wrong cell.
+    pub(crate) fn chord(&mut self, row: usize, col: usize) {
+        if self.game_over || self.won || !self.grid[row][col].is_revealed {
+            return;
+        }
+
+        let mut flagged_count = 0;
+        self.for_each_adjacent_cell(row, col, |r, c| {
+            if self.grid[r][c].mark == Mark::Flag {
+                flagged_count += 1;
+            }
+        });
+
+        if flagged_count != self.grid[row][col].adjacent_mines {
+            return;
+        }
+
+        // Collect neighbors first to avoid borrowing issues while revealing
+        for (r, c) in self.neighbor_coords(row, col) {
+            if !self.grid[r][c].is_revealed && self.grid[r][c].mark != Mark::Flag {
+                self.reveal_cell(r, c);
+            }
+        }
+    }
+
+    // Check if the player has won
+    fn check_win(&mut self) {
+        // Using the unrevealed_non_mine_count to check for win condition
+        if self.unrevealed_non_mine_count == 0 {
+            // Check if all mines are flagged
+            for row in 0..self.difficulty.rows {
+                for col in 0..self.difficulty.cols {
+                    if self.grid[row][col].is_mine && self.grid[row][col].mark != Mark::Flag {
+                        return;
+                    }
+                }
+            }
+            self.won = true;
+            self.just_finished = true;
+        }
+    }
+}
@@ -1,9 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+// Mark represents the player-assigned state of an unrevealed cell
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Mark {
+    None,
+    Flag,
+    Question,
+}
+
+impl Mark {
+    // Cycle to the next mark in the classic Minesweeper order
+    pub fn next(self) -> Self {
+        match self {
+            Mark::None => Mark::Flag,
+            Mark::Flag => Mark::Question,
+            Mark::Question => Mark::None,
+        }
+    }
+}
+
 // Cell represents a single cell in the minesweeper grid
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 pub struct Cell {
     pub is_mine: bool,
     pub is_revealed: bool,
-    pub is_flagged: bool,
+    pub mark: Mark,
     pub adjacent_mines: u8,
 }
 
@@ -12,8 +33,8 @@ impl Cell {
         Cell {
             is_mine: false,
             is_revealed: false,
-            is_flagged: false,
+            mark: Mark::None,
             adjacent_mines: 0,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::constants::{CELL_SIZE, STATUS_BAR_HEIGHT};
+
+// Difficulty defines the board dimensions and mine count for a game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Difficulty {
+    pub rows: usize,
+    pub cols: usize,
+    pub mines: usize,
+}
+
+impl Difficulty {
+    pub const BEGINNER: Difficulty = Difficulty { rows: 9, cols: 9, mines: 10 };
+    pub const INTERMEDIATE: Difficulty = Difficulty { rows: 16, cols: 16, mines: 40 };
+    pub const EXPERT: Difficulty = Difficulty { rows: 24, cols: 24, mines: 99 };
+
+    // The window dimensions (in pixels) needed to render a board of this difficulty.
+    pub fn screen_size(&self) -> (f32, f32) {
+        let width = self.cols as f32 * CELL_SIZE;
+        let height = self.rows as f32 * CELL_SIZE + STATUS_BAR_HEIGHT;
+        (width, height)
+    }
+
+    // A stable key identifying this difficulty, used to key persisted best times.
+    pub fn label(&self) -> String {
+        if *self == Difficulty::BEGINNER {
+            "beginner".to_string()
+        } else if *self == Difficulty::INTERMEDIATE {
+            "intermediate".to_string()
+        } else if *self == Difficulty::EXPERT {
+            "expert".to_string()
+        } else {
+            format!("custom_{}x{}x{}", self.rows, self.cols, self.mines)
+        }
+    }
+}
@@ -1,215 +1,134 @@
 use ggez::{Context, GameResult};
 use ggez::graphics::{self, Color, DrawParam, Rect, Text};
 use ggez::event::{EventHandler, MouseButton};
-use rand::{self, Rng};
+use ggez::input::keyboard::{KeyCode, KeyInput};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
 
 use crate::utils::constants::*;
-use crate::core::cell::Cell;
+use crate::core::board::Board;
+use crate::core::cell::Mark;
+use crate::core::difficulty::Difficulty;
+use crate::core::persistence;
+use crate::core::text_renderer;
+
+// The subset of GameState that fully determines the game; everything else
+// (text cache, best time) is transient or re-derived on load.
+#[derive(Serialize, Deserialize)]
+struct SavedGame {
+    board: Board,
+    elapsed_secs: f32,
+    timer_running: bool,
+}
 
-// GameState represents the current state of the minesweeper game
+// GameState wraps the headless `Board` with everything ggez needs: a cached
+// set of `Text` objects and the wall-clock timer. The solver and text
+// renderer operate on `Board` directly and never see these fields.
 pub struct GameState {
-    grid: Vec<Vec<Cell>>,
-    game_over: bool,
-    won: bool,
+    board: Board,
     text_cache: HashMap<String, Text>,
-    unrevealed_non_mine_count: usize,
+    // Elapsed play time, ticking from the first reveal until the game ends.
+    elapsed_secs: f32,
+    timer_running: bool,
+    best_time: Option<f32>,
 }
 
 impl GameState {
-    pub fn new() -> Self {
-        let mut state = GameState {
-            grid: vec![vec![Cell::new(); GRID_SIZE]; GRID_SIZE],
-            game_over: false,
-            won: false,
+    pub fn new(difficulty: Difficulty) -> Self {
+        GameState {
+            board: Board::new(difficulty),
             text_cache: HashMap::new(),
-            unrevealed_non_mine_count: GRID_SIZE * GRID_SIZE,
-        };
-
-        state.place_mines();
-        state.calculate_adjacent_mines();
-
-        // Update unrevealed_non_mine_count after placing mines
-        state.unrevealed_non_mine_count -= MINE_COUNT;
-
-        // Reveal a safe starting area
-        state.reveal_safe_starting_area();
-
-        state
-    }
-
-    // Reveal a safe starting area for the player
-    fn reveal_safe_starting_area(&mut self) {
-        let mut min_adjacent = 9;
-        let mut min_row = 0;
-        let mut min_col = 0;
-
-        // Single pass through the grid to find both zero and minimum adjacent mines
-        for row in 0..GRID_SIZE {
-            for col in 0..GRID_SIZE {
-                if !self.grid[row][col].is_mine {
-                    if self.grid[row][col].adjacent_mines == 0 {
-                        // Found a cell with zero adjacent mines, reveal it and return
-                        self.reveal_cell(row, col);
-                        return;
-                    } else if self.grid[row][col].adjacent_mines < min_adjacent {
-                        // Keep track of the cell with minimum adjacent mines
-                        min_adjacent = self.grid[row][col].adjacent_mines;
-                        min_row = row;
-                        min_col = col;
-                    }
-                }
-            }
+            elapsed_secs: 0.0,
+            timer_running: false,
+            best_time: persistence::best_time(&difficulty),
         }
-
-        // If no cell with zero adjacent mines was found, reveal the one with minimum
-        self.reveal_cell(min_row, min_col);
     }
 
-    // Place mines randomly on the grid
-    fn place_mines(&mut self) {
-        let mut rng = rand::thread_rng();
-        let mut mines_placed = 0;
-
-        while mines_placed < MINE_COUNT {
-            let row = rng.gen_range(0..GRID_SIZE);
-            let col = rng.gen_range(0..GRID_SIZE);
+    // Stop the timer and, on a win, persist a new best time if one was set.
+    fn finish_game(&mut self) {
+        self.timer_running = false;
 
-            if !self.grid[row][col].is_mine {
-                self.grid[row][col].is_mine = true;
-                mines_placed += 1;
-            }
+        if self.board.won {
+            persistence::record_best_time(&self.board.difficulty, self.elapsed_secs);
         }
-    }
-
-    // Calculate the number of adjacent mines for each cell
-    fn calculate_adjacent_mines(&mut self) {
-        for row in 0..GRID_SIZE {
-            for col in 0..GRID_SIZE {
-                if self.grid[row][col].is_mine {
-                    continue;
-                }
 
-                let mut count = 0;
-                self.for_each_adjacent_cell(row, col, |r, c| {
-                    if self.grid[r][c].is_mine {
-                        count += 1;
-                    }
-                });
+        self.best_time = persistence::best_time(&self.board.difficulty);
+    }
 
-                self.grid[row][col].adjacent_mines = count;
-            }
+    // If `self.board.just_finished` was just set by a move, stop the timer,
+    // persist a best time on a win, and clear the flag.
+    fn handle_finish(&mut self) {
+        if self.board.just_finished {
+            self.finish_game();
+            self.board.just_finished = false;
         }
     }
 
-    // Reveal a cell and handle the result
+    // Reveal a cell, starting the timer on the first reveal of the game and
+    // reacting if that reveal just ended the game.
     fn reveal_cell(&mut self, row: usize, col: usize) {
-        if self.game_over || self.won || self.grid[row][col].is_revealed || self.grid[row][col].is_flagged {
-            return;
-        }
+        let had_placed_mines = self.board.mines_placed;
+        self.board.reveal_cell(row, col);
 
-        self.grid[row][col].is_revealed = true;
-
-        // If it's a mine, game over
-        if self.grid[row][col].is_mine {
-            self.game_over = true;
-            return;
+        if !had_placed_mines && self.board.mines_placed {
+            self.timer_running = true;
         }
-
-        // Decrement the unrevealed non-mine count
-        self.unrevealed_non_mine_count -= 1;
-
-        // If it's a cell with no adjacent mines, reveal adjacent cells
-        if self.grid[row][col].adjacent_mines == 0 {
-            // Collect adjacent cells to avoid borrowing issues
-            let mut adjacent_cells = Vec::new();
-
-            for dr in -1..=1 {
-                for dc in -1..=1 {
-                    if dr == 0 && dc == 0 {
-                        continue;
-                    }
-
-                    let new_row = row as isize + dr;
-                    let new_col = col as isize + dc;
-
-                    if new_row >= 0 && new_row < GRID_SIZE as isize && 
-                       new_col >= 0 && new_col < GRID_SIZE as isize {
-                        adjacent_cells.push((new_row as usize, new_col as usize));
-                    }
-                }
-            }
-
-            // Process collected cells
-            for (new_row, new_col) in adjacent_cells {
-                if !self.grid[new_row][new_col].is_revealed && !self.grid[new_row][new_col].is_flagged {
-                    self.reveal_cell(new_row, new_col);
-                }
-            }
-        }
-
-        // Check if the player has won
-        self.check_win();
+        self.handle_finish();
     }
 
-    // Toggle flag on a cell
-    fn toggle_flag(&mut self, row: usize, col: usize) {
-        if self.game_over || self.won || self.grid[row][col].is_revealed {
-            return;
-        }
-
-        self.grid[row][col].is_flagged = !self.grid[row][col].is_flagged;
-
-        // Check if the player has won
-        self.check_win();
+    fn cycle_mark(&mut self, row: usize, col: usize) {
+        self.board.cycle_mark(row, col);
+        self.handle_finish();
     }
 
-    // Helper function to iterate over adjacent cells
-    fn for_each_adjacent_cell<F>(&self, row: usize, col: usize, mut callback: F)
-    where
-        F: FnMut(usize, usize),
-    {
-        for dr in -1..=1 {
-            for dc in -1..=1 {
-                if dr == 0 && dc == 0 {
-                    continue;
-                }
+    fn chord(&mut self, row: usize, col: usize) {
+        self.board.chord(row, col);
+        self.handle_finish();
+    }
 
-                let new_row = row as isize + dr;
-                let new_col = col as isize + dc;
+    // Dump the board to `path` so the player can quit and resume later.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let saved = SavedGame {
+            board: self.board.clone(),
+            elapsed_secs: self.elapsed_secs,
+            timer_running: self.timer_running,
+        };
 
-                if new_row >= 0 && new_row < GRID_SIZE as isize && 
-                   new_col >= 0 && new_col < GRID_SIZE as isize {
-                    callback(new_row as usize, new_col as usize);
-                }
-            }
-        }
+        let contents = serde_json::to_string_pretty(&saved).map_err(io::Error::other)?;
+        fs::write(path, contents)
     }
 
-    // Check if the player has won
-    fn check_win(&mut self) {
-        // Using the unrevealed_non_mine_count to check for win condition
-        if self.unrevealed_non_mine_count == 0 {
-            // Check if all mines are flagged
-            for row in 0..GRID_SIZE {
-                for col in 0..GRID_SIZE {
-                    if self.grid[row][col].is_mine && !self.grid[row][col].is_flagged {
-                        return;
-                    }
-                }
-            }
-            self.won = true;
-        }
+    // Reconstruct a game previously written by `save`.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let saved: SavedGame = serde_json::from_str(&contents)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        Ok(GameState {
+            best_time: persistence::best_time(&saved.board.difficulty),
+            board: saved.board,
+            text_cache: HashMap::new(),
+            elapsed_secs: saved.elapsed_secs,
+            timer_running: saved.timer_running,
+        })
     }
 }
 
 impl EventHandler for GameState {
-    fn update(&mut self, _ctx: &mut Context) -> GameResult {
+    fn update(&mut self, ctx: &mut Context) -> GameResult {
+        if self.timer_running {
+            self.elapsed_secs += ctx.time.delta().as_secs_f32();
+        }
+
         Ok(())
     }
 
     fn draw(&mut self, ctx: &mut Context) -> GameResult {
         let mut canvas = graphics::Canvas::from_frame(ctx, Color::WHITE);
+        let (_, screen_height) = self.board.difficulty.screen_size();
 
         // Cache text objects if not already cached
         if self.text_cache.is_empty() {
@@ -219,6 +138,9 @@ impl EventHandler for GameState {
             // Cache flag emoji
             self.text_cache.insert("flag".to_string(), Text::new("ðŸš©"));
 
+            // Cache question mark
+            self.text_cache.insert("question".to_string(), Text::new("?"));
+
             // Cache numbers 1-8
             for i in 1..=8 {
                 self.text_cache.insert(i.to_string(), Text::new(i.to_string()));
@@ -231,9 +153,9 @@ impl EventHandler for GameState {
         }
 
         // Draw the grid
-        for row in 0..GRID_SIZE {
-            for col in 0..GRID_SIZE {
-                let cell = self.grid[row][col];
+        for row in 0..self.board.difficulty.rows {
+            for col in 0..self.board.difficulty.cols {
+                let cell = self.board.grid[row][col];
                 let x = col as f32 * CELL_SIZE;
                 let y = row as f32 * CELL_SIZE;
 
@@ -289,7 +211,7 @@ impl EventHandler for GameState {
                                 .color(Color::BLACK),
                         );
                     }
-                } else if cell.is_flagged {
+                } else if cell.mark == Mark::Flag {
                     // Draw flag using cached text
                     canvas.draw(
                         self.text_cache.get("flag").unwrap(),
@@ -297,14 +219,22 @@ impl EventHandler for GameState {
                             .dest([x + CELL_SIZE / 4.0, y + CELL_SIZE / 4.0])
                             .color(Color::BLACK),
                     );
+                } else if cell.mark == Mark::Question {
+                    // Draw question mark using cached text
+                    canvas.draw(
+                        self.text_cache.get("question").unwrap(),
+                        DrawParam::default()
+                            .dest([x + CELL_SIZE / 3.0, y + CELL_SIZE / 4.0])
+                            .color(Color::BLACK),
+                    );
                 }
             }
         }
 
         // Draw game status using cached text
-        let status_key = if self.game_over {
+        let status_key = if self.board.game_over {
             "game_over"
-        } else if self.won {
+        } else if self.board.won {
             "won"
         } else {
             "playing"
@@ -313,10 +243,36 @@ impl EventHandler for GameState {
         canvas.draw(
             self.text_cache.get(status_key).unwrap(),
             DrawParam::default()
-                .dest([10.0, SCREEN_HEIGHT - 40.0])
+                .dest([10.0, screen_height - 40.0])
+                .color(Color::BLACK),
+        );
+
+        // Timer and remaining-mine count change every frame, so they are
+        // rendered fresh rather than pulled from the text cache.
+        let hud_text = Text::new(format!(
+            "Time: {:.0}s  Mines: {}",
+            self.elapsed_secs,
+            self.board.mines_remaining()
+        ));
+        canvas.draw(
+            &hud_text,
+            DrawParam::default()
+                .dest([10.0, screen_height - 20.0])
                 .color(Color::BLACK),
         );
 
+        if self.board.won {
+            if let Some(best_time) = self.best_time {
+                let best_text = Text::new(format!("Best: {:.0}s", best_time));
+                canvas.draw(
+                    &best_text,
+                    DrawParam::default()
+                        .dest([200.0, screen_height - 40.0])
+                        .color(Color::BLACK),
+                );
+            }
+        }
+
         canvas.finish(ctx)?;
 
         // Limit to 60 FPS
@@ -333,10 +289,11 @@ impl EventHandler for GameState {
         y: f32,
     ) -> GameResult {
         // If game is over or won, restart the game
-        if self.game_over || self.won {
-            // Preserve the text cache when restarting
+        if self.board.game_over || self.board.won {
+            // Preserve the text cache and difficulty when restarting
             let text_cache = std::mem::take(&mut self.text_cache);
-            *self = GameState::new();
+            let difficulty = self.board.difficulty;
+            *self = GameState::new(difficulty);
             self.text_cache = text_cache;
             return Ok(());
         }
@@ -346,14 +303,128 @@ impl EventHandler for GameState {
         let row = (y / CELL_SIZE) as usize;
 
         // Ensure the click is within the grid
-        if row < GRID_SIZE && col < GRID_SIZE {
+        if row < self.board.difficulty.rows && col < self.board.difficulty.cols {
             match button {
                 MouseButton::Left => self.reveal_cell(row, col),
-                MouseButton::Right => self.toggle_flag(row, col),
+                MouseButton::Right => self.cycle_mark(row, col),
+                MouseButton::Middle => self.chord(row, col),
                 _ => {}
             }
         }
 
         Ok(())
     }
+
+    // F5 saves the game in progress; F9 loads the most recent save,
+    // preserving the text cache the same way a restart does.
+    fn key_down_event(&mut self, ctx: &mut Context, input: KeyInput, _repeated: bool) -> GameResult {
+        match input.keycode {
+            Some(KeyCode::F5) => {
+                if let Some(path) = persistence::save_game_path() {
+                    let _ = self.save(&path);
+                }
+            }
+            Some(KeyCode::F9) => {
+                if let Some(path) = persistence::save_game_path() {
+                    if let Ok(loaded) = GameState::load(&path) {
+                        // Resize the window to match the loaded difficulty,
+                        // which may differ from the one currently playing.
+                        let (width, height) = loaded.board.difficulty.screen_size();
+                        ctx.gfx.set_mode(ggez::conf::WindowMode::default().dimensions(width, height))?;
+
+                        let text_cache = std::mem::take(&mut self.text_cache);
+                        *self = loaded;
+                        self.text_cache = text_cache;
+                    }
+                }
+            }
+            // Auto-play hint: run the constraint-propagation solver as far
+            // as it can go without guessing.
+            Some(KeyCode::H) => {
+                self.board.solve();
+                self.handle_finish();
+            }
+            // Dump the board to stdout using the headless text renderer,
+            // handy for debugging without a screenshot.
+            Some(KeyCode::D) => {
+                print!("{}", text_renderer::render(&self.board));
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Revealing any cell, including corners and edges, must never place a
+    // mine on the clicked cell or its 8 neighbors.
+    #[test]
+    fn place_mines_keeps_the_clicked_cell_and_its_neighbors_safe() {
+        let difficulty = Difficulty { rows: 9, cols: 9, mines: 10 };
+        let click_positions = [(0, 0), (0, 8), (8, 0), (8, 8), (4, 4), (0, 4), (4, 0)];
+
+        for (row, col) in click_positions {
+            let mut game = GameState::new(difficulty);
+            game.reveal_cell(row, col);
+
+            for dr in -1isize..=1 {
+                for dc in -1isize..=1 {
+                    let r = row as isize + dr;
+                    let c = col as isize + dc;
+
+                    if r < 0 || c < 0 || r as usize >= difficulty.rows || c as usize >= difficulty.cols {
+                        continue;
+                    }
+
+                    assert!(
+                        !game.board.grid[r as usize][c as usize].is_mine,
+                        "mine placed inside the safe zone around ({row}, {col})"
+                    );
+                }
+            }
+        }
+    }
+
+    // A hand-built 3x3 board with a single mine in the bottom-right corner.
+    // Unlike a randomly-placed board (which isn't always solvable by pure
+    // deduction), this layout is fully determined by constraint propagation,
+    // so the solver's behavior can be asserted exactly.
+    fn single_corner_mine_game() -> GameState {
+        let difficulty = Difficulty { rows: 3, cols: 3, mines: 1 };
+        let mut game = GameState::new(difficulty);
+
+        game.board.grid[2][2].is_mine = true;
+        game.board.calculate_adjacent_mines();
+        game.board.mines_placed = true;
+
+        game
+    }
+
+    #[test]
+    fn solver_fully_clears_a_deterministic_board() {
+        let mut game = single_corner_mine_game();
+
+        game.reveal_cell(0, 0);
+        assert!(
+            game.board.solve(),
+            "solver should fully clear a board solvable by pure deduction"
+        );
+
+        for row in game.board.grid.iter() {
+            for cell in row.iter() {
+                if cell.mark == Mark::Flag {
+                    assert!(cell.is_mine, "solver flagged a safe cell");
+                }
+                if cell.is_revealed {
+                    assert!(!cell.is_mine, "solver revealed a mine");
+                }
+            }
+        }
+
+        assert!(game.board.won, "the only mine should end up flagged, winning the game");
+    }
 }
@@ -0,0 +1,8 @@
+pub mod app;
+pub mod board;
+pub mod cell;
+pub mod difficulty;
+pub mod game;
+pub mod persistence;
+pub mod solver;
+pub mod text_renderer;
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::difficulty::Difficulty;
+
+// Best completion times, keyed by `Difficulty::label`, persisted as JSON in
+// the user's config directory.
+#[derive(Default, Serialize, Deserialize)]
+struct BestTimes {
+    #[serde(flatten)]
+    by_difficulty: HashMap<String, f32>,
+}
+
+fn best_times_path() -> Option<PathBuf> {
+    let mut dir = config_dir()?;
+    dir.push("best_times.json");
+    Some(dir)
+}
+
+fn config_dir() -> Option<PathBuf> {
+    let mut dir = dirs::config_dir()?;
+    dir.push("rust-minesweeper");
+    fs::create_dir_all(&dir).ok()?;
+    Some(dir)
+}
+
+// Path to the in-progress save file, so a player can quit and resume later.
+pub fn save_game_path() -> Option<PathBuf> {
+    let mut dir = config_dir()?;
+    dir.push("save.json");
+    Some(dir)
+}
+
+fn load_best_times() -> BestTimes {
+    best_times_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_best_times(best_times: &BestTimes) -> io::Result<()> {
+    let path = best_times_path().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+    let contents = serde_json::to_string_pretty(best_times).map_err(io::Error::other)?;
+    fs::write(path, contents)
+}
+
+// Returns the current best time for a difficulty, if one has been recorded.
+pub fn best_time(difficulty: &Difficulty) -> Option<f32> {
+    load_best_times().by_difficulty.get(&difficulty.label()).copied()
+}
+
+// Records `elapsed_secs` as the new best for this difficulty if it beats (or
+// sets) the stored best.
+pub fn record_best_time(difficulty: &Difficulty, elapsed_secs: f32) {
+    let mut best_times = load_best_times();
+    let key = difficulty.label();
+    let is_new_best = best_times
+        .by_difficulty
+        .get(&key)
+        .is_none_or(|best| elapsed_secs < *best);
+
+    if is_new_best {
+        best_times.by_difficulty.insert(key, elapsed_secs);
+        let _ = save_best_times(&best_times);
+    }
+}
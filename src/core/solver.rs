@@ -0,0 +1,58 @@
+use crate::core::board::Board;
+use crate::core::cell::Mark;
+
+impl Board {
+    // Repeatedly applies two constraint-propagation deductions over every
+    // revealed numbered cell until neither makes progress:
+    //   - if a number equals its count of unrevealed neighbors, they must
+    //     all be mines, so flag them;
+    //   - if a number equals its count of already-flagged neighbors, the
+    //     remaining unrevealed neighbors must be safe, so reveal them.
+    //
+    // Returns whether the board ended up fully solved without guessing.
+    // Useful both as an auto-play hint and as an integration check that a
+    // generated board is solvable.
+    pub fn solve(&mut self) -> bool {
+        loop {
+            let mut made_progress = false;
+
+            for row in 0..self.difficulty.rows {
+                for col in 0..self.difficulty.cols {
+                    if !self.grid[row][col].is_revealed || self.grid[row][col].adjacent_mines == 0 {
+                        continue;
+                    }
+
+                    let number = self.grid[row][col].adjacent_mines as usize;
+                    let mut unrevealed = Vec::new();
+                    let mut flagged = 0;
+
+                    self.for_each_adjacent_cell(row, col, |r, c| {
+                        if self.grid[r][c].mark == Mark::Flag {
+                            flagged += 1;
+                        } else if !self.grid[r][c].is_revealed {
+                            unrevealed.push((r, c));
+                        }
+                    });
+
+                    if flagged + unrevealed.len() == number {
+                        for (r, c) in unrevealed {
+                            self.flag_cell(r, c);
+                            made_progress = true;
+                        }
+                    } else if flagged == number {
+                        for (r, c) in unrevealed {
+                            self.reveal_cell(r, c);
+                            made_progress = true;
+                        }
+                    }
+                }
+            }
+
+            if !made_progress {
+                break;
+            }
+        }
+
+        self.won || self.unrevealed_non_mine_count == 0
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Write;
+
+use crate::core::board::Board;
+use crate::core::cell::Mark;
+
+// Renders the board as text using the Rosetta Code convention: '.' for an
+// obscured cell, '?' for a flagged cell, a space for a revealed cell with no
+// adjacent mines, and a digit for a revealed cell's adjacent mine count. A
+// revealed mine (only possible after a loss) is shown as '*'.
+pub fn render(board: &Board) -> String {
+    let mut output = String::new();
+
+    for row in 0..board.difficulty.rows {
+        for col in 0..board.difficulty.cols {
+            let cell = board.grid[row][col];
+
+            let glyph = if !cell.is_revealed {
+                match cell.mark {
+                    Mark::Flag => '?',
+                    Mark::None | Mark::Question => '.',
+                }
+            } else if cell.is_mine {
+                '*'
+            } else if cell.adjacent_mines == 0 {
+                ' '
+            } else {
+                char::from_digit(cell.adjacent_mines as u32, 10).unwrap_or('?')
+            };
+
+            output.push(glyph);
+        }
+
+        let _ = writeln!(output);
+    }
+
+    output
+}
@@ -4,18 +4,22 @@ mod utils;
 use ggez::{ContextBuilder, GameResult};
 use ggez::event;
 
-use utils::constants::{SCREEN_WIDTH, SCREEN_HEIGHT};
-use core::game::GameState;
+use core::app::App;
+
+// Initial window size for the difficulty menu; the game resizes the window
+// once a difficulty has been chosen.
+const MENU_WIDTH: f32 = 400.0;
+const MENU_HEIGHT: f32 = 160.0;
 
 fn main() -> GameResult {
     // Create a new context builder
     let (ctx, event_loop) = ContextBuilder::new("minesweeper", "author")
         .window_setup(ggez::conf::WindowSetup::default().title("Minesweeper"))
-        .window_mode(ggez::conf::WindowMode::default().dimensions(SCREEN_WIDTH, SCREEN_HEIGHT))
+        .window_mode(ggez::conf::WindowMode::default().dimensions(MENU_WIDTH, MENU_HEIGHT))
         .build()?;
 
-    // Create a new game state
-    let state = GameState::new();
+    // Create a new app state, starting on the difficulty menu
+    let state = App::new();
 
     // Run the game
     event::run(ctx, event_loop, state)